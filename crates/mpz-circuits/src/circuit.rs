@@ -1,7 +1,7 @@
 use itybity::IntoBits;
 
 use crate::{
-    components::Gate,
+    components::{Gate, GateType},
     types::{BinaryRepr, TypeError, Value},
 };
 
@@ -15,6 +15,14 @@ pub enum CircuitError {
     InvalidOutputCount(usize, usize),
     #[error(transparent)]
     TypeError(#[from] TypeError),
+    #[error("Node {0} is used before it is defined")]
+    UndefinedNode(usize),
+    #[error("Node {0} is defined more than once")]
+    RedefinedNode(usize),
+    #[error("Node {0} is out of range: feed count is {1}")]
+    NodeOutOfRange(usize, usize),
+    #[error("Invalid {0:?} gate count: circuit reports {1}, found {2}")]
+    InvalidGateCount(GateType, usize, usize),
 }
 
 /// A binary circuit.
@@ -109,6 +117,85 @@ impl Circuit {
         self
     }
 
+    /// Checks that the circuit is well-formed.
+    ///
+    /// A circuit is well-formed if:
+    ///
+    /// * every node is within the feed count of the circuit,
+    /// * every node is defined exactly once, either as an input or as the output of a gate,
+    /// * every node is defined before it is used by another gate or as an output of the circuit,
+    /// * the reported AND and XOR gate counts match the gates of the circuit.
+    ///
+    /// This is useful for circuits from an untrusted source, eg. ones which were deserialized.
+    pub fn validate(&self) -> Result<(), CircuitError> {
+        let mut defined = vec![false; self.feed_count];
+
+        let define = |defined: &mut [bool], id: usize| -> Result<(), CircuitError> {
+            let is_defined = defined
+                .get_mut(id)
+                .ok_or(CircuitError::NodeOutOfRange(id, self.feed_count))?;
+
+            if *is_defined {
+                return Err(CircuitError::RedefinedNode(id));
+            }
+
+            *is_defined = true;
+
+            Ok(())
+        };
+
+        let check_defined = |defined: &[bool], id: usize| -> Result<(), CircuitError> {
+            match defined.get(id) {
+                Some(true) => Ok(()),
+                Some(false) => Err(CircuitError::UndefinedNode(id)),
+                None => Err(CircuitError::NodeOutOfRange(id, self.feed_count)),
+            }
+        };
+
+        for node in self.inputs.iter().flat_map(|input| input.iter()) {
+            define(&mut defined, node.id)?;
+        }
+
+        let mut and_count = 0;
+        let mut xor_count = 0;
+        for gate in self.gates.iter() {
+            check_defined(&defined, gate.x().id)?;
+            if let Some(y) = gate.y() {
+                check_defined(&defined, y.id)?;
+            }
+
+            define(&mut defined, gate.z().id)?;
+
+            match gate.gate_type() {
+                GateType::And => and_count += 1,
+                GateType::Xor => xor_count += 1,
+                GateType::Inv => {}
+            }
+        }
+
+        for node in self.outputs.iter().flat_map(|output| output.iter()) {
+            check_defined(&defined, node.id)?;
+        }
+
+        if and_count != self.and_count {
+            return Err(CircuitError::InvalidGateCount(
+                GateType::And,
+                self.and_count,
+                and_count,
+            ));
+        }
+
+        if xor_count != self.xor_count {
+            return Err(CircuitError::InvalidGateCount(
+                GateType::Xor,
+                self.xor_count,
+                xor_count,
+            ));
+        }
+
+        Ok(())
+    }
+
     /// Evaluate the circuit with the given inputs.
     ///
     /// # Arguments
@@ -221,4 +308,93 @@ mod tests {
 
         assert_eq!(out, 3u8);
     }
+
+    #[test]
+    fn test_validate() {
+        let circ = build_adder();
+
+        assert!(circ.validate().is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "aes")]
+    fn test_validate_aes128() {
+        assert!(crate::circuits::AES128.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_out_of_order_gates() {
+        let mut circ = build_adder();
+
+        // The final gate of the adder depends on the carry of the previous bits.
+        circ.gates.reverse();
+
+        assert!(matches!(
+            circ.validate(),
+            Err(CircuitError::UndefinedNode(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_out_of_range_node() {
+        let mut circ = build_adder();
+
+        let max_id = circ.gates.iter().map(|gate| gate.z().id).max().unwrap();
+        circ.feed_count = max_id;
+
+        assert!(matches!(
+            circ.validate(),
+            Err(CircuitError::NodeOutOfRange(id, feed_count)) if id == max_id && feed_count == max_id
+        ));
+    }
+
+    #[test]
+    fn test_validate_redefined_input() {
+        let mut circ = build_adder();
+
+        // Overwrite an input with the output of the first gate.
+        let input_id = circ.inputs[0].iter().next().unwrap().id;
+        match &mut circ.gates[0] {
+            Gate::Xor { z, .. } | Gate::And { z, .. } | Gate::Inv { z, .. } => z.id = input_id,
+        }
+
+        assert!(matches!(
+            circ.validate(),
+            Err(CircuitError::RedefinedNode(id)) if id == input_id
+        ));
+    }
+
+    #[test]
+    fn test_validate_redefined_gate_output() {
+        let mut circ = build_adder();
+
+        let first_z = circ.gates[0].z().id;
+        match circ.gates.last_mut().unwrap() {
+            Gate::Xor { z, .. } | Gate::And { z, .. } | Gate::Inv { z, .. } => z.id = first_z,
+        }
+
+        assert!(matches!(
+            circ.validate(),
+            Err(CircuitError::RedefinedNode(id)) if id == first_z
+        ));
+    }
+
+    #[test]
+    fn test_validate_invalid_gate_count() {
+        let mut circ = build_adder();
+        circ.and_count += 1;
+
+        assert!(matches!(
+            circ.validate(),
+            Err(CircuitError::InvalidGateCount(GateType::And, _, _))
+        ));
+
+        let mut circ = build_adder();
+        circ.xor_count -= 1;
+
+        assert!(matches!(
+            circ.validate(),
+            Err(CircuitError::InvalidGateCount(GateType::Xor, _, _))
+        ));
+    }
 }
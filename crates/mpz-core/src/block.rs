@@ -108,6 +108,19 @@ impl Block {
         self.0[0] |= 1;
     }
 
+    /// Clears the least significant bit of the block
+    #[inline]
+    pub fn clear_lsb(&mut self) {
+        self.0[0] &= 0xfe;
+    }
+
+    /// Returns a copy of the block with the least significant bit set to `bit`
+    #[inline]
+    pub fn with_lsb(mut self, bit: bool) -> Self {
+        self.0[0] = (self.0[0] & 0xfe) | bit as u8;
+        self
+    }
+
     /// Returns the least significant bit of the block
     #[inline]
     pub fn lsb(&self) -> usize {
@@ -345,6 +358,39 @@ mod tests {
         assert_eq!(a.lsb(), 1);
     }
 
+    #[test]
+    fn test_clear_lsb() {
+        let mut b = Block::ONES;
+        b.clear_lsb();
+        assert_eq!(b.lsb(), 0);
+
+        // only the least significant bit is affected
+        let mut expected = [0xff; 16];
+        expected[0] = 0xfe;
+        assert_eq!(Block::new(expected), b);
+
+        // no-op when the bit is already clear
+        let mut b = Block::ZERO;
+        b.clear_lsb();
+        assert_eq!(Block::ZERO, b);
+    }
+
+    #[test]
+    fn test_with_lsb() {
+        let mut three = [0; 16];
+        three[0] = 3;
+        let mut two = [0; 16];
+        two[0] = 2;
+
+        assert_eq!(Block::new(three).with_lsb(false), Block::new(two));
+        assert_eq!(Block::new(two).with_lsb(true), Block::new(three));
+        assert_eq!(Block::new(three).with_lsb(true), Block::new(three));
+        assert_eq!(Block::new(two).with_lsb(false), Block::new(two));
+
+        let b = Block::new([0xa5; 16]);
+        assert_eq!(b.with_lsb(b.lsb() == 1), b);
+    }
+
     #[test]
     fn test_reverse_bits() {
         let a = Block::new([42; 16]);
@@ -0,0 +1,229 @@
+//! Bandwidth estimation for garbled circuits.
+
+use std::collections::BTreeSet;
+
+use mpz_circuits::{types::BinaryRepr, Circuit};
+
+use crate::{BYTES_PER_GATE, DEFAULT_BATCH_SIZE};
+
+/// Bytes per label.
+const BYTES_PER_LABEL: usize = 16;
+/// Bytes per bit of an encoding commitment, one hash for each label.
+const BYTES_PER_COMMITMENT: usize = 32;
+/// Bytes per bit of an OT sender message, one label for each choice.
+const BYTES_PER_OT_MESSAGE: usize = 2 * BYTES_PER_LABEL;
+
+/// An estimate of the bytes sent by the generator to evaluate a circuit.
+///
+/// The estimate covers the payloads only, it does not account for serialization framing or the
+/// setup and extension overhead of the oblivious transfer protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BandwidthEstimate {
+    /// Bytes of encrypted gates, including the padding of the final batch.
+    pub garbled_circuit: usize,
+    /// Bytes of active input labels sent directly for the generator's inputs.
+    pub input_labels: usize,
+    /// Bytes of OT sender messages for the evaluator's inputs, two labels per bit.
+    pub ot_messages: usize,
+    /// Bytes of output encoding commitments, if they are used.
+    pub encoding_commitments: usize,
+    /// Bytes of output decodings.
+    pub decodings: usize,
+}
+
+impl BandwidthEstimate {
+    /// Returns the total bytes, excluding encoding commitments.
+    pub fn total(&self) -> usize {
+        self.garbled_circuit + self.input_labels + self.ot_messages + self.decodings
+    }
+
+    /// Returns the total bytes, including encoding commitments.
+    pub fn total_with_commitments(&self) -> usize {
+        self.total() + self.encoding_commitments
+    }
+}
+
+/// Estimates the bytes sent by the generator to evaluate the provided circuit.
+///
+/// # Arguments
+///
+/// * `circ` - The circuit to estimate.
+/// * `evaluator_inputs` - The indices of the circuit inputs which are provided by the evaluator,
+///   the labels for these are sent using oblivious transfer. Repeated indices are counted once.
+///
+/// # Panics
+///
+/// Panics if an index in `evaluator_inputs` is out of range.
+pub fn estimate_bandwidth(circ: &Circuit, evaluator_inputs: &[usize]) -> BandwidthEstimate {
+    let batch_count = circ.and_count().div_ceil(DEFAULT_BATCH_SIZE);
+    let input_bits: usize = circ.inputs().iter().map(BinaryRepr::len).sum();
+    let evaluator_input_bits: usize = evaluator_inputs
+        .iter()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .map(|idx| circ.inputs()[*idx].len())
+        .sum();
+    let output_bits: usize = circ.outputs().iter().map(BinaryRepr::len).sum();
+
+    BandwidthEstimate {
+        garbled_circuit: batch_count * DEFAULT_BATCH_SIZE * BYTES_PER_GATE,
+        input_labels: (input_bits - evaluator_input_bits) * BYTES_PER_LABEL,
+        ot_messages: evaluator_input_bits * BYTES_PER_OT_MESSAGE,
+        encoding_commitments: output_bits * BYTES_PER_COMMITMENT,
+        decodings: circ.outputs().iter().map(decoding_len).sum(),
+    }
+}
+
+/// Returns the length in bytes of the decoding of a value.
+fn decoding_len(repr: &BinaryRepr) -> usize {
+    match repr {
+        BinaryRepr::Array(elems) => elems.iter().map(decoding_len).sum(),
+        repr => repr.len().div_ceil(8),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mpz_circuits::{circuits::AES128, CircuitBuilder};
+
+    use super::*;
+    use crate::{ChaChaEncoder, Encoder, Generator};
+
+    /// Upper bound on the bincode framing of a single serialized value, eg. an enum tag and a
+    /// length prefix, which the estimate does not account for.
+    const FRAMING_PER_VALUE: usize = 16;
+
+    /// Asserts that the estimate does not exceed the serialized size, and that the difference is
+    /// within the framing overhead of the provided number of values.
+    fn assert_within(estimate: usize, actual: usize, values: usize) {
+        assert!(
+            estimate <= actual && actual - estimate <= values * FRAMING_PER_VALUE,
+            "estimate {estimate} is not within {} bytes of {actual}",
+            values * FRAMING_PER_VALUE
+        );
+    }
+
+    fn serialized_size<T: serde::Serialize>(value: &T) -> usize {
+        bincode::serialized_size(value).unwrap() as usize
+    }
+
+    #[test]
+    fn test_estimate_bandwidth_aes() {
+        // The key is provided by the generator, the message by the evaluator.
+        let estimate = estimate_bandwidth(&AES128, &[1]);
+
+        let encoder = ChaChaEncoder::new([0; 32]);
+        let inputs: Vec<_> = AES128
+            .inputs()
+            .iter()
+            .map(|input| encoder.encode_by_type(0, &input.value_type()))
+            .collect();
+
+        let input_labels = serialized_size(&inputs[0].select([69u8; 16]).unwrap());
+        let ot_messages = serialized_size(&inputs[1].iter_blocks().collect::<Vec<_>>());
+
+        let mut gen = Generator::default();
+        let mut iter = gen
+            .generate_batched(&AES128, encoder.delta(), inputs)
+            .unwrap();
+
+        let mut batch_count = 0;
+        let mut garbled_circuit = 0;
+        for batch in iter.by_ref() {
+            batch_count += 1;
+            garbled_circuit += serialized_size(&batch);
+        }
+
+        let output = iter.finish().unwrap();
+
+        let commitments: Vec<_> = output
+            .outputs
+            .iter()
+            .map(|output| output.commit())
+            .collect();
+        let decodings: Vec<_> = output
+            .outputs
+            .iter()
+            .map(|output| output.decoding())
+            .collect();
+
+        // The ciphertext is an array of 16 bytes, each element is framed separately.
+        let output_values = 1 + 16;
+
+        assert_within(estimate.garbled_circuit, garbled_circuit, batch_count);
+        assert_within(estimate.input_labels, input_labels, 1 + 16);
+        assert_within(estimate.ot_messages, ot_messages, 1);
+        assert_within(
+            estimate.encoding_commitments,
+            serialized_size(&commitments),
+            output_values + 1,
+        );
+        assert_within(
+            estimate.decodings,
+            serialized_size(&decodings),
+            output_values + 1,
+        );
+        assert_eq!(
+            estimate.total(),
+            estimate.garbled_circuit
+                + estimate.input_labels
+                + estimate.ot_messages
+                + estimate.decodings
+        );
+    }
+
+    #[test]
+    fn test_estimate_bandwidth_no_and() {
+        let builder = CircuitBuilder::new();
+
+        let a = builder.add_input::<u8>();
+        let b = builder.add_input::<u8>();
+
+        builder.add_output(a ^ b);
+
+        let circ = builder.build().unwrap();
+
+        let estimate = estimate_bandwidth(&circ, &[]);
+
+        assert_eq!(estimate.garbled_circuit, 0);
+        assert_eq!(estimate.input_labels, 16 * BYTES_PER_LABEL);
+        assert_eq!(estimate.ot_messages, 0);
+        assert_eq!(estimate.decodings, 1);
+    }
+
+    #[test]
+    fn test_estimate_bandwidth_evaluator_inputs() {
+        let builder = CircuitBuilder::new();
+
+        let a = builder.add_input::<u8>();
+        let b = builder.add_input::<u32>();
+
+        builder.add_output(a);
+        builder.add_output(b);
+
+        let circ = builder.build().unwrap();
+
+        let estimate = estimate_bandwidth(&circ, &[1]);
+
+        assert_eq!(estimate.input_labels, 8 * BYTES_PER_LABEL);
+        assert_eq!(estimate.ot_messages, 32 * BYTES_PER_OT_MESSAGE);
+    }
+
+    #[test]
+    fn test_estimate_bandwidth_repeated_evaluator_input() {
+        let builder = CircuitBuilder::new();
+
+        let a = builder.add_input::<u8>();
+        let b = builder.add_input::<u32>();
+
+        builder.add_output(a);
+        builder.add_output(b);
+
+        let circ = builder.build().unwrap();
+
+        assert_eq!(
+            estimate_bandwidth(&circ, &[1, 1]),
+            estimate_bandwidth(&circ, &[1])
+        );
+    }
+}
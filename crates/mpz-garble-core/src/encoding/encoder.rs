@@ -6,7 +6,7 @@ use rand_core::OsRng;
 
 use super::{state, value::Encode, Delta, EncodedValue, Label};
 
-const DELTA_STREAM_ID: u64 = u64::MAX;
+pub(super) const DELTA_STREAM_ID: u64 = u64::MAX;
 
 /// This trait is used to encode values using a global offset (delta).
 ///
@@ -51,12 +51,7 @@ impl ChaChaEncoder {
     ///
     /// * `seed` - 32-byte seed for ChaChaRng
     pub fn new(seed: [u8; 32]) -> Self {
-        let mut rng = ChaCha20Rng::from_seed(seed);
-
-        // Stream id u64::MAX is reserved to generate delta.
-        // This way there is only ever 1 delta per seed
-        rng.set_stream(DELTA_STREAM_ID);
-        let delta = Delta::random(&mut rng);
+        let delta = Delta::from_seed(seed);
 
         Self { seed, delta }
     }
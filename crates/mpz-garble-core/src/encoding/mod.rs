@@ -23,13 +23,24 @@ use std::{
 };
 
 use mpz_core::Block;
-use rand::{CryptoRng, Rng};
+use rand::{CryptoRng, Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
 use serde::{Deserialize, Deserializer, Serialize};
 
 pub use encoder::{ChaChaEncoder, Encoder};
 pub use equality::EqualityCheck;
 pub use value::{Decoding, Encode, EncodedValue, EncodingCommitment, ValueError};
 
+/// Errors that can occur when constructing a [`Delta`].
+#[derive(Debug, thiserror::Error)]
+#[allow(missing_docs)]
+pub enum DeltaError {
+    #[error("invalid delta length, expected: 16, actual: {0}")]
+    InvalidLength(usize),
+    #[error("invalid delta, the pointer bit must be set")]
+    InvalidPointerBit,
+}
+
 /// Global binary offset used by the Free-XOR technique to create label
 /// pairs where W_1 = W_0 ^ Delta.
 ///
@@ -46,6 +57,37 @@ impl Delta {
         Self(block)
     }
 
+    /// Derives Delta deterministically from a seed
+    ///
+    /// This is the same Delta used by a [`ChaChaEncoder`] created with the same seed.
+    pub fn from_seed(seed: [u8; 32]) -> Self {
+        let mut rng = ChaCha20Rng::from_seed(seed);
+
+        // Stream id u64::MAX is reserved to generate delta.
+        // This way there is only ever 1 delta per seed
+        rng.set_stream(encoder::DELTA_STREAM_ID);
+
+        Self::random(&mut rng)
+    }
+
+    /// Returns the pointer bit of Delta from the Point-and-Permute technique
+    ///
+    /// This is always `true` for a valid Delta.
+    #[inline]
+    pub fn pointer_bit(&self) -> bool {
+        self.0.lsb() == 1
+    }
+
+    /// Asserts that the pointer bit of Delta is set
+    ///
+    /// This is a debug-only sanity check which does nothing in release builds. To construct
+    /// Delta from untrusted input, eg. bytes received from another party, use
+    /// [`Delta::try_from`] which rejects a Delta without the pointer bit set.
+    #[inline]
+    pub fn assert_invariant(&self) {
+        debug_assert!(self.pointer_bit(), "pointer bit of delta must be set");
+    }
+
     /// Returns the inner block
     #[inline]
     pub(crate) fn into_inner(self) -> Block {
@@ -53,6 +95,32 @@ impl Delta {
     }
 }
 
+impl TryFrom<[u8; 16]> for Delta {
+    type Error = DeltaError;
+
+    fn try_from(bytes: [u8; 16]) -> Result<Self, Self::Error> {
+        let delta = Self(Block::new(bytes));
+
+        if !delta.pointer_bit() {
+            return Err(DeltaError::InvalidPointerBit);
+        }
+
+        Ok(delta)
+    }
+}
+
+impl TryFrom<&[u8]> for Delta {
+    type Error = DeltaError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; 16] = bytes
+            .try_into()
+            .map_err(|_| DeltaError::InvalidLength(bytes.len()))?;
+
+        Self::try_from(bytes)
+    }
+}
+
 impl Deref for Delta {
     type Target = Block;
 
@@ -370,3 +438,76 @@ impl From<Block> for Label {
         Self(block)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delta_from_seed() {
+        let delta = Delta::from_seed([0u8; 32]);
+
+        assert_eq!(delta, Delta::from_seed([0u8; 32]));
+        assert_ne!(delta, Delta::from_seed([1u8; 32]));
+        assert_eq!(delta.lsb(), 1);
+        assert_eq!(delta, ChaChaEncoder::new([0u8; 32]).delta());
+    }
+
+    #[test]
+    fn test_delta_invariant() {
+        let mut rng = ChaCha20Rng::seed_from_u64(0);
+        let delta = Delta::random(&mut rng);
+
+        assert!(delta.pointer_bit());
+        delta.assert_invariant();
+
+        let delta = Delta::from_seed([0u8; 32]);
+
+        assert!(delta.pointer_bit());
+        delta.assert_invariant();
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn test_delta_invariant_violated() {
+        let delta = Delta(Block::ZERO);
+
+        assert!(!delta.pointer_bit());
+        delta.assert_invariant();
+    }
+
+    #[test]
+    fn test_delta_try_from_bytes() {
+        let delta = Delta::from_seed([0u8; 32]);
+        let bytes = delta.to_bytes();
+
+        assert_eq!(Delta::try_from(bytes).unwrap(), delta);
+        assert_eq!(Delta::try_from(&bytes[..]).unwrap(), delta);
+    }
+
+    #[test]
+    fn test_delta_try_from_bytes_invalid_length() {
+        let bytes = [1u8; 17];
+
+        assert!(matches!(
+            Delta::try_from(&bytes[..]),
+            Err(DeltaError::InvalidLength(17))
+        ));
+        assert!(matches!(
+            Delta::try_from(&bytes[..15]),
+            Err(DeltaError::InvalidLength(15))
+        ));
+    }
+
+    #[test]
+    fn test_delta_try_from_bytes_invalid_pointer_bit() {
+        let mut bytes = Delta::from_seed([0u8; 32]).to_bytes();
+        bytes[0] &= 0xfe;
+
+        assert!(matches!(
+            Delta::try_from(bytes),
+            Err(DeltaError::InvalidPointerBit)
+        ));
+    }
+}
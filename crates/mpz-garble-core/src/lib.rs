@@ -47,15 +47,17 @@
 #![deny(missing_docs, unreachable_pub, unused_must_use)]
 #![deny(clippy::all)]
 
+mod bandwidth;
 pub(crate) mod circuit;
 pub mod encoding;
 mod evaluator;
 mod generator;
 
+pub use bandwidth::{estimate_bandwidth, BandwidthEstimate};
 pub use circuit::{EncryptedGate, EncryptedGateBatch, GarbledCircuit};
 pub use encoding::{
-    state as encoding_state, ChaChaEncoder, Decoding, Delta, Encode, EncodedValue, Encoder,
-    EncodingCommitment, EqualityCheck, Label, ValueError,
+    state as encoding_state, ChaChaEncoder, Decoding, Delta, DeltaError, Encode, EncodedValue,
+    Encoder, EncodingCommitment, EqualityCheck, Label, ValueError,
 };
 pub use evaluator::{
     EncryptedGateBatchConsumer, EncryptedGateConsumer, Evaluator, EvaluatorError, EvaluatorOutput,
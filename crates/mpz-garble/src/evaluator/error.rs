@@ -15,6 +15,8 @@ pub enum EvaluatorError {
     OTError(Box<mpz_ot::OTError>),
     #[error("incorrect number of values: expected {expected}, got {actual}")]
     IncorrectValueCount { expected: usize, actual: usize },
+    #[error("incorrect number of encrypted gates: expected {expected}, got {actual}")]
+    IncorrectGateCount { expected: usize, actual: usize },
     #[error(transparent)]
     TypeError(#[from] mpz_circuits::types::TypeError),
     #[error(transparent)]
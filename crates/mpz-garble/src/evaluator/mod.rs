@@ -374,56 +374,15 @@ impl Evaluator {
             outputs: outputs.to_vec(),
         };
 
-        let encoded_inputs = {
-            let state = self.state();
-            inputs
-                .iter()
-                .map(|value_ref| {
-                    state
-                        .memory
-                        .get_encoding(value_ref)
-                        .ok_or_else(|| EvaluatorError::MissingEncoding(value_ref.clone()))
-                })
-                .collect::<Result<Vec<_>, _>>()?
-        };
+        let encoded_inputs = self.get_encodings(inputs)?;
 
         let existing_garbled_circuit = self.state().garbled_circuits.remove(&refs);
 
         // If we've already received the garbled circuit, we evaluate it, otherwise we stream the encrypted gates
         // from the generator.
-        let EvaluatorOutput {
-            outputs: encoded_outputs,
-            hash,
-        } = if let Some(GarbledCircuit { gates, commitments }) = existing_garbled_circuit {
-            let circ = circ.clone();
-            let hash = self.config.log_circuits;
-            let output = CpuBackend::blocking(move || {
-                let mut ev = EvaluatorCore::default();
-                let mut ev_consumer = ev.evaluate(&circ, encoded_inputs)?;
-
-                if hash {
-                    ev_consumer.enable_hasher();
-                }
-
-                for gate in gates {
-                    ev_consumer.next(gate);
-                }
-
-                ev_consumer.finish().map_err(EvaluatorError::from)
-            })
-            .await?;
-
-            if self.config.encoding_commitments {
-                for (output, commitment) in output
-                    .outputs
-                    .iter()
-                    .zip(commitments.expect("commitments were checked to be present"))
-                {
-                    commitment.verify(output)?;
-                }
-            }
-
-            output
+        let output = if let Some(garbled) = existing_garbled_circuit {
+            self.evaluate_garbled(circ.clone(), garbled, encoded_inputs)
+                .await?
         } else {
             let circ = circ.clone();
             let hash = self.config.log_circuits;
@@ -465,6 +424,106 @@ impl Evaluator {
             output
         };
 
+        self.set_outputs(circ, inputs, outputs, output)
+    }
+
+    /// Evaluates a garbled circuit which is already in memory, eg. loaded from a cache.
+    ///
+    /// Unlike [`Evaluator::evaluate`], this does not communicate with the generator.
+    ///
+    /// Returns the encoded outputs of the evaluated circuit.
+    ///
+    /// # Arguments
+    ///
+    /// * `circ` - The circuit to evaluate
+    /// * `garbled` - The garbled circuit
+    /// * `inputs` - The inputs to the circuit.
+    /// * `outputs` - The outputs from the circuit.
+    #[tracing::instrument(skip_all, err)]
+    pub async fn evaluate_in_memory(
+        &self,
+        circ: Arc<Circuit>,
+        garbled: GarbledCircuit,
+        inputs: &[ValueRef],
+        outputs: &[ValueRef],
+    ) -> Result<Vec<EncodedValue<encoding_state::Active>>, EvaluatorError> {
+        if garbled.gates.len() != circ.and_count() {
+            return Err(EvaluatorError::IncorrectGateCount {
+                expected: circ.and_count(),
+                actual: garbled.gates.len(),
+            });
+        }
+
+        // If configured, make sure the garbled circuit carries the output encoding commitments.
+        if self.config.encoding_commitments {
+            let actual = garbled.commitments.as_ref().map_or(0, Vec::len);
+            if actual != circ.outputs().len() {
+                return Err(EvaluatorError::IncorrectValueCount {
+                    expected: circ.outputs().len(),
+                    actual,
+                });
+            }
+        }
+
+        let encoded_inputs = self.get_encodings(inputs)?;
+        let output = self
+            .evaluate_garbled(circ.clone(), garbled, encoded_inputs)
+            .await?;
+
+        self.set_outputs(circ, inputs, outputs, output)
+    }
+
+    /// Evaluates a garbled circuit whose encrypted gates have already been received.
+    async fn evaluate_garbled(
+        &self,
+        circ: Arc<Circuit>,
+        garbled: GarbledCircuit,
+        encoded_inputs: Vec<EncodedValue<encoding_state::Active>>,
+    ) -> Result<EvaluatorOutput, EvaluatorError> {
+        let GarbledCircuit { gates, commitments } = garbled;
+        let hash = self.config.log_circuits;
+        let output = CpuBackend::blocking(move || {
+            let mut ev = EvaluatorCore::default();
+            let mut ev_consumer = ev.evaluate(&circ, encoded_inputs)?;
+
+            if hash {
+                ev_consumer.enable_hasher();
+            }
+
+            for gate in gates {
+                ev_consumer.next(gate);
+            }
+
+            ev_consumer.finish().map_err(EvaluatorError::from)
+        })
+        .await?;
+
+        if self.config.encoding_commitments {
+            for (output, commitment) in output
+                .outputs
+                .iter()
+                .zip(commitments.expect("commitments were checked to be present"))
+            {
+                commitment.verify(output)?;
+            }
+        }
+
+        Ok(output)
+    }
+
+    /// Stores the encoded outputs of an evaluated circuit, logging the evaluation if configured.
+    fn set_outputs(
+        &self,
+        circ: Arc<Circuit>,
+        inputs: &[ValueRef],
+        outputs: &[ValueRef],
+        output: EvaluatorOutput,
+    ) -> Result<Vec<EncodedValue<encoding_state::Active>>, EvaluatorError> {
+        let EvaluatorOutput {
+            outputs: encoded_outputs,
+            hash,
+        } = output;
+
         // Add the output encodings to the memory.
         let mut state = self.state();
         for (output, encoding) in outputs.iter().zip(encoded_outputs.iter()) {
@@ -619,6 +678,28 @@ impl Evaluator {
     }
 }
 
+/// Checks that the active output encodings of a circuit open the generator's encoding commitments.
+///
+/// This does not require evaluating the circuit again, so it can be used by a third party, eg. to
+/// audit the outputs of an earlier evaluation.
+///
+/// Returns `true` if every output opens its corresponding commitment.
+///
+/// # Arguments
+///
+/// * `outputs` - The active encodings of the circuit outputs.
+/// * `commitments` - The encoding commitments sent by the generator.
+pub fn check_output_commitments(
+    outputs: &[EncodedValue<encoding_state::Active>],
+    commitments: &[EncodingCommitment],
+) -> bool {
+    outputs.len() == commitments.len()
+        && outputs
+            .iter()
+            .zip(commitments)
+            .all(|(output, commitment)| commitment.verify(output).is_ok())
+}
+
 #[derive(Debug)]
 pub(crate) struct EvaluatorLog {
     inputs: Vec<ValueRef>,
@@ -642,3 +723,38 @@ impl EvaluatorLog {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use mpz_circuits::types::StaticValueType;
+    use mpz_garble_core::{ChaChaEncoder, Encoder};
+
+    use super::*;
+
+    #[test]
+    fn test_check_output_commitments() {
+        let encoder = ChaChaEncoder::new([0u8; 32]);
+
+        let full = [
+            encoder.encode_by_type(0, &<[u8; 16]>::value_type()),
+            encoder.encode_by_type(1, &ValueType::U32),
+        ];
+        let commitments: Vec<_> = full.iter().map(|value| value.commit()).collect();
+
+        let active = vec![
+            full[0].select([42u8; 16]).unwrap(),
+            full[1].select(69u32).unwrap(),
+        ];
+
+        assert!(check_output_commitments(&active, &commitments));
+
+        // Labels for a different value do not open the commitment.
+        let mut tampered = active.clone();
+        tampered[1] = full[1].select(70u32).unwrap();
+
+        assert!(!check_output_commitments(&tampered, &commitments));
+
+        // Commitments must be provided for every output.
+        assert!(!check_output_commitments(&active, &commitments[..1]));
+    }
+}
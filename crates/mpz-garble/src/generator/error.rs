@@ -23,6 +23,8 @@ pub enum GeneratorError {
     MissingEncoding(ValueRef),
     #[error(transparent)]
     EncodingRegistryError(#[from] crate::memory::EncodingMemoryError),
+    #[error("input is not encoded with the provided delta")]
+    DeltaMismatch,
 }
 
 impl From<mpz_ot::OTError> for GeneratorError {
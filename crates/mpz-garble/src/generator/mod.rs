@@ -16,8 +16,8 @@ use mpz_circuits::{
 use mpz_common::{scoped, Context};
 use mpz_core::hash::Hash;
 use mpz_garble_core::{
-    encoding_state, ChaChaEncoder, EncodedValue, Encoder, EncodingCommitment,
-    Generator as GeneratorCore, GeneratorOutput,
+    encoding_state, ChaChaEncoder, Delta, EncodedValue, Encoder, EncodingCommitment,
+    GarbledCircuit, Generator as GeneratorCore, GeneratorOutput,
 };
 use serio::SinkExt;
 use tracing::{span, Level};
@@ -382,6 +382,51 @@ impl Generator {
     }
 }
 
+/// Garbles a circuit without a connection to an evaluator, eg. to cache it for later evaluation.
+///
+/// Returns the garbled circuit, which includes commitments to the output encodings, along with
+/// the full encodings of the outputs. The garbled circuit can later be evaluated with
+/// [`Evaluator::evaluate_in_memory`](crate::Evaluator::evaluate_in_memory).
+///
+/// # Arguments
+///
+/// * `circ` - The circuit to garble.
+/// * `delta` - The global offset used to encode the inputs.
+/// * `inputs` - The full encodings of the circuit inputs.
+///
+/// # Errors
+///
+/// Returns an error if any of the inputs is not encoded with `delta`.
+pub fn garble_offline(
+    circ: &Circuit,
+    delta: Delta,
+    inputs: Vec<EncodedValue<encoding_state::Full>>,
+) -> Result<(GarbledCircuit, GeneratorOutput), GeneratorError> {
+    if inputs.iter().any(|input| input.delta() != delta) {
+        return Err(GeneratorError::DeltaMismatch);
+    }
+
+    let mut gen = GeneratorCore::default();
+    let mut gate_iter = gen.generate(circ, delta, inputs)?;
+
+    let gates: Vec<_> = gate_iter.by_ref().collect();
+    let output = gate_iter.finish()?;
+
+    let commitments = output
+        .outputs
+        .iter()
+        .map(|output| output.commit())
+        .collect();
+
+    Ok((
+        GarbledCircuit {
+            gates,
+            commitments: Some(commitments),
+        },
+        output,
+    ))
+}
+
 impl State {
     fn new(encoder: ChaChaEncoder) -> Self {
         Self {
@@ -23,9 +23,14 @@ pub mod ot;
 pub mod protocol;
 pub mod value;
 
-pub use evaluator::{Evaluator, EvaluatorConfig, EvaluatorConfigBuilder, EvaluatorError};
-pub use generator::{Generator, GeneratorConfig, GeneratorConfigBuilder, GeneratorError};
+pub use evaluator::{
+    check_output_commitments, Evaluator, EvaluatorConfig, EvaluatorConfigBuilder, EvaluatorError,
+};
+pub use generator::{
+    garble_offline, Generator, GeneratorConfig, GeneratorConfigBuilder, GeneratorError,
+};
 pub use memory::{AssignedValues, ValueMemory};
+pub use mpz_garble_core::{estimate_bandwidth, BandwidthEstimate};
 
 use value::{ArrayRef, ValueId, ValueRef};
 
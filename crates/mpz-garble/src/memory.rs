@@ -509,6 +509,30 @@ mod tests {
         assert!(matches!(err, MemoryError::DuplicateValueId(_)));
     }
 
+    #[test]
+    fn test_value_memory_assign_after_drain_fails() {
+        let mut memory = ValueMemory::default();
+
+        let value = memory
+            .new_input("test", u8::value_type(), Visibility::Private)
+            .unwrap();
+
+        memory.assign(&value, 1u8.into()).unwrap();
+
+        // Draining the assigned values, eg. when committing them, must not allow the
+        // value to be assigned again.
+        let assigned = memory.drain_assigned(&[value.clone()]);
+        assert_eq!(assigned.private.len(), 1);
+
+        let err = memory.assign(&value, 2u8.into()).unwrap_err();
+
+        assert!(matches!(
+            err,
+            MemoryError::Assignment(AssignmentError::Duplicate(_))
+        ));
+        assert!(memory.drain_assigned(&[value]).private.is_empty());
+    }
+
     #[rstest]
     #[case::bit(PhantomData::<bool>)]
     #[case::u8(PhantomData::<u8>)]
@@ -0,0 +1,210 @@
+use mpz_circuits::{circuits::AES128, types::StaticValueType};
+use mpz_common::{executor::test_st_executor, Context};
+use mpz_garble_core::{EncryptedGate, EncryptedGateBatch, GarbledCircuit};
+use mpz_ot::ideal::ot::ideal_ot;
+use serio::stream::IoStreamExt;
+
+use mpz_garble::{
+    config::Visibility, Evaluator, EvaluatorError, Generator, GeneratorConfigBuilder, ValueMemory,
+};
+
+#[tokio::test]
+async fn test_in_memory_evaluate() {
+    let (mut ctx_a, mut ctx_b) = test_st_executor(8);
+    let (mut ot_send, mut ot_recv) = ideal_ot();
+
+    let gen = Generator::new(
+        GeneratorConfigBuilder::default().build().unwrap(),
+        [0u8; 32],
+    );
+    let ev = Evaluator::default();
+
+    let key = [69u8; 16];
+    let msg = [42u8; 16];
+
+    let key_typ = <[u8; 16]>::value_type();
+    let msg_typ = <[u8; 16]>::value_type();
+    let ciphertext_typ = <[u8; 16]>::value_type();
+
+    let gen_fut = async {
+        let mut memory = ValueMemory::default();
+
+        let key_ref = memory
+            .new_input("key", key_typ.clone(), Visibility::Private)
+            .unwrap();
+        let msg_ref = memory
+            .new_input("msg", msg_typ.clone(), Visibility::Blind)
+            .unwrap();
+        let streamed_ref = memory
+            .new_output("streamed", ciphertext_typ.clone())
+            .unwrap();
+        let in_memory_ref = memory
+            .new_output("in_memory", ciphertext_typ.clone())
+            .unwrap();
+
+        memory.assign(&key_ref, key.into()).unwrap();
+
+        gen.generate_input_encoding(&key_ref, &key_typ);
+        gen.generate_input_encoding(&msg_ref, &msg_typ);
+
+        gen.setup_assigned_values(
+            &mut ctx_a,
+            &memory.drain_assigned(&[key_ref.clone(), msg_ref.clone()]),
+            &mut ot_send,
+        )
+        .await
+        .unwrap();
+
+        for output_ref in [&streamed_ref, &in_memory_ref] {
+            gen.generate(
+                &mut ctx_a,
+                AES128.clone(),
+                &[key_ref.clone(), msg_ref.clone()],
+                &[output_ref.clone()],
+                false,
+            )
+            .await
+            .unwrap();
+        }
+
+        (
+            gen.get_encoding(&streamed_ref).unwrap(),
+            gen.get_encoding(&in_memory_ref).unwrap(),
+        )
+    };
+
+    let ev_fut = async {
+        let mut memory = ValueMemory::default();
+
+        let key_ref = memory
+            .new_input("key", key_typ.clone(), Visibility::Blind)
+            .unwrap();
+        let msg_ref = memory
+            .new_input("msg", msg_typ.clone(), Visibility::Private)
+            .unwrap();
+        let streamed_ref = memory
+            .new_output("streamed", ciphertext_typ.clone())
+            .unwrap();
+        let in_memory_ref = memory
+            .new_output("in_memory", ciphertext_typ.clone())
+            .unwrap();
+
+        memory.assign(&msg_ref, msg.into()).unwrap();
+
+        ev.setup_assigned_values(
+            &mut ctx_b,
+            &memory.drain_assigned(&[key_ref.clone(), msg_ref.clone()]),
+            &mut ot_recv,
+        )
+        .await
+        .unwrap();
+
+        _ = ev
+            .evaluate(
+                &mut ctx_b,
+                AES128.clone(),
+                &[key_ref.clone(), msg_ref.clone()],
+                &[streamed_ref.clone()],
+            )
+            .await
+            .unwrap();
+
+        // Buffer the entire garbled circuit before evaluating it.
+        let gate_count = AES128.and_count();
+        let mut gates = Vec::with_capacity(gate_count);
+        while gates.len() < gate_count {
+            let batch: EncryptedGateBatch = ctx_b.io_mut().expect_next().await.unwrap();
+            gates.extend_from_slice(&batch.into_array());
+        }
+        gates.truncate(gate_count);
+
+        _ = ev
+            .evaluate_in_memory(
+                AES128.clone(),
+                GarbledCircuit {
+                    gates,
+                    commitments: None,
+                },
+                &[key_ref.clone(), msg_ref.clone()],
+                &[in_memory_ref.clone()],
+            )
+            .await
+            .unwrap();
+
+        (
+            ev.get_encoding(&streamed_ref).unwrap(),
+            ev.get_encoding(&in_memory_ref).unwrap(),
+        )
+    };
+
+    let (
+        (streamed_full_encoding, in_memory_full_encoding),
+        (streamed_active_encoding, in_memory_active_encoding),
+    ) = tokio::join!(gen_fut, ev_fut);
+
+    let streamed: [u8; 16] = streamed_active_encoding
+        .decode(&streamed_full_encoding.decoding())
+        .unwrap()
+        .try_into()
+        .unwrap();
+    let in_memory: [u8; 16] = in_memory_active_encoding
+        .decode(&in_memory_full_encoding.decoding())
+        .unwrap()
+        .try_into()
+        .unwrap();
+
+    let expected: [u8; 16] = {
+        use aes::{
+            cipher::{BlockEncrypt, KeyInit},
+            Aes128,
+        };
+
+        let mut msg = msg.into();
+
+        let cipher = Aes128::new_from_slice(&key).unwrap();
+        cipher.encrypt_block(&mut msg);
+
+        msg.into()
+    };
+
+    assert_eq!(in_memory, streamed);
+    assert_eq!(in_memory, expected);
+}
+
+#[tokio::test]
+async fn test_in_memory_evaluate_incorrect_gate_count() {
+    let ev = Evaluator::default();
+
+    let mut memory = ValueMemory::default();
+    let key_ref = memory
+        .new_input("key", <[u8; 16]>::value_type(), Visibility::Blind)
+        .unwrap();
+    let msg_ref = memory
+        .new_input("msg", <[u8; 16]>::value_type(), Visibility::Blind)
+        .unwrap();
+    let ciphertext_ref = memory
+        .new_output("ciphertext", <[u8; 16]>::value_type())
+        .unwrap();
+
+    let gate_count = AES128.and_count();
+    for actual in [gate_count - 1, gate_count + 1] {
+        let err = ev
+            .evaluate_in_memory(
+                AES128.clone(),
+                GarbledCircuit {
+                    gates: vec![EncryptedGate::default(); actual],
+                    commitments: None,
+                },
+                &[key_ref.clone(), msg_ref.clone()],
+                &[ciphertext_ref.clone()],
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            EvaluatorError::IncorrectGateCount { expected, actual: got }
+                if expected == gate_count && got == actual
+        ));
+    }
+}
@@ -1,8 +1,12 @@
 use mpz_circuits::{circuits::AES128, types::StaticValueType};
 use mpz_common::executor::test_st_executor;
+use mpz_garble_core::{ChaChaEncoder, Delta, Encoder, GarbledCircuit};
 use mpz_ot::ideal::ot::ideal_ot;
 
-use mpz_garble::{config::Visibility, Evaluator, Generator, GeneratorConfigBuilder, ValueMemory};
+use mpz_garble::{
+    config::Visibility, garble_offline, Evaluator, EvaluatorConfigBuilder, Generator,
+    GeneratorConfigBuilder, GeneratorError, ValueMemory,
+};
 
 #[tokio::test]
 async fn test_offline_garble() {
@@ -131,3 +135,132 @@ async fn test_offline_garble() {
 
     assert_eq!(ciphertext, expected)
 }
+
+#[tokio::test]
+async fn test_garble_offline_serialized() {
+    let (mut ctx_a, mut ctx_b) = test_st_executor(8);
+    let (mut ot_send, mut ot_recv) = ideal_ot();
+
+    let seed = [0u8; 32];
+    let gen = Generator::new(GeneratorConfigBuilder::default().build().unwrap(), seed);
+    let ev = Evaluator::new(
+        EvaluatorConfigBuilder::default()
+            .encoding_commitments()
+            .build()
+            .unwrap(),
+    );
+
+    let key = [69u8; 16];
+    let msg = [42u8; 16];
+
+    let key_typ = <[u8; 16]>::value_type();
+    let msg_typ = <[u8; 16]>::value_type();
+    let ciphertext_typ = <[u8; 16]>::value_type();
+
+    let mut gen_memory = ValueMemory::default();
+    let key_ref = gen_memory
+        .new_input("key", key_typ.clone(), Visibility::Private)
+        .unwrap();
+    let msg_ref = gen_memory
+        .new_input("msg", msg_typ.clone(), Visibility::Blind)
+        .unwrap();
+
+    gen.generate_input_encoding(&key_ref, &key_typ);
+    gen.generate_input_encoding(&msg_ref, &msg_typ);
+
+    // Garble the circuit ahead of time, and cache it.
+    let inputs = gen
+        .get_encodings(&[key_ref.clone(), msg_ref.clone()])
+        .unwrap();
+    let (garbled, output) = garble_offline(&AES128, inputs[0].delta(), inputs).unwrap();
+    let cached = bincode::serialize(&garbled).unwrap();
+
+    let gen_fut = async {
+        gen_memory.assign(&key_ref, key.into()).unwrap();
+
+        gen.setup_assigned_values(
+            &mut ctx_a,
+            &gen_memory.drain_assigned(&[key_ref.clone(), msg_ref.clone()]),
+            &mut ot_send,
+        )
+        .await
+        .unwrap();
+    };
+
+    let ev_fut = async {
+        let mut memory = ValueMemory::default();
+
+        let key_ref = memory
+            .new_input("key", key_typ.clone(), Visibility::Blind)
+            .unwrap();
+        let msg_ref = memory
+            .new_input("msg", msg_typ.clone(), Visibility::Private)
+            .unwrap();
+        let ciphertext_ref = memory
+            .new_output("ciphertext", ciphertext_typ.clone())
+            .unwrap();
+
+        memory.assign(&msg_ref, msg.into()).unwrap();
+
+        ev.setup_assigned_values(
+            &mut ctx_b,
+            &memory.drain_assigned(&[key_ref.clone(), msg_ref.clone()]),
+            &mut ot_recv,
+        )
+        .await
+        .unwrap();
+
+        let garbled: GarbledCircuit = bincode::deserialize(&cached).unwrap();
+
+        _ = ev
+            .evaluate_in_memory(
+                AES128.clone(),
+                garbled,
+                &[key_ref.clone(), msg_ref.clone()],
+                &[ciphertext_ref.clone()],
+            )
+            .await
+            .unwrap();
+
+        ev.get_encoding(&ciphertext_ref).unwrap()
+    };
+
+    let (_, ciphertext_active_encoding) = tokio::join!(gen_fut, ev_fut);
+
+    let decoding = output.outputs[0].decoding();
+    let ciphertext: [u8; 16] = ciphertext_active_encoding
+        .decode(&decoding)
+        .unwrap()
+        .try_into()
+        .unwrap();
+
+    let expected: [u8; 16] = {
+        use aes::{
+            cipher::{BlockEncrypt, KeyInit},
+            Aes128,
+        };
+
+        let mut msg = msg.into();
+
+        let cipher = Aes128::new_from_slice(&key).unwrap();
+        cipher.encrypt_block(&mut msg);
+
+        msg.into()
+    };
+
+    assert_eq!(ciphertext, expected)
+}
+
+#[test]
+fn test_garble_offline_delta_mismatch() {
+    let encoder = ChaChaEncoder::new([0u8; 32]);
+    let inputs: Vec<_> = AES128
+        .inputs()
+        .iter()
+        .map(|input| encoder.encode_by_type(0, &input.value_type()))
+        .collect();
+
+    let err = garble_offline(&AES128, Delta::from_seed([1u8; 32]), inputs).unwrap_err();
+
+    assert!(matches!(err, GeneratorError::DeltaMismatch));
+}
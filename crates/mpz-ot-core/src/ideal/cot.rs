@@ -7,6 +7,14 @@ use rand_chacha::ChaCha8Rng;
 use crate::TransferId;
 use crate::{COTReceiverOutput, COTSenderOutput, RCOTReceiverOutput, RCOTSenderOutput};
 
+/// Errors that can occur when using the ideal COT functionality.
+#[derive(Debug, Clone, thiserror::Error)]
+#[allow(missing_docs)]
+pub enum IdealCOTError {
+    #[error("length mismatch: sender expected {0} COTs, receiver provided {1} choices")]
+    LengthMismatch(usize, usize),
+}
+
 /// The ideal COT functionality.
 #[derive(Debug)]
 pub struct IdealCOT {
@@ -120,6 +128,25 @@ impl IdealCOT {
             },
         )
     }
+
+    /// Executes correlated oblivious transfers, checking that the number of COTs expected by
+    /// the sender matches the number of choices provided by the receiver.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - The number of COTs expected by the sender.
+    /// * `choices` - The choices made by the receiver.
+    pub fn try_correlated(
+        &mut self,
+        count: usize,
+        choices: Vec<bool>,
+    ) -> Result<(COTSenderOutput<Block>, COTReceiverOutput<Block>), IdealCOTError> {
+        if count != choices.len() {
+            return Err(IdealCOTError::LengthMismatch(count, choices.len()));
+        }
+
+        Ok(self.correlated(choices))
+    }
 }
 
 impl Default for IdealCOT {
@@ -164,4 +191,14 @@ mod tests {
 
         assert_cot(ideal.delta(), &choices, &msgs, &received)
     }
+
+    #[test]
+    fn test_ideal_cot_length_mismatch() {
+        let mut ideal = IdealCOT::default();
+
+        let err = ideal.try_correlated(100, vec![false; 99]).unwrap_err();
+
+        assert!(matches!(err, IdealCOTError::LengthMismatch(100, 99)));
+        assert_eq!(ideal.count(), 0);
+    }
 }
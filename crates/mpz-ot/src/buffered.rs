@@ -0,0 +1,280 @@
+//! Buffered random correlated oblivious transfer.
+//!
+//! Wrappers which pull random COTs from an underlying protocol in fixed-size batches, and then
+//! hand them out in whatever amounts the caller requests. This is useful for protocols which
+//! consume correlations a few at a time.
+//!
+//! The sender and receiver must be configured with the same batch size, and request the same
+//! counts in the same order, so that both parties draw from the underlying protocol in lockstep.
+//!
+//! The transfer id of each output is the id of the most recent transfer from the underlying
+//! protocol. An output may contain correlations from several underlying transfers, and outputs
+//! served entirely from the buffer repeat the id of an earlier output, so the id does not uniquely
+//! identify the returned correlations.
+
+use async_trait::async_trait;
+use mpz_common::Context;
+
+use crate::{
+    OTError, OTSetup, RCOTReceiverOutput, RCOTSenderOutput, RandomCOTReceiver, RandomCOTSender,
+    TransferId,
+};
+
+/// A random COT sender which buffers correlations from an underlying sender.
+///
+/// See the [module level documentation](self) for the meaning of the transfer ids it returns.
+#[derive(Debug)]
+pub struct BufferedRCOTSender<OT, T> {
+    inner: OT,
+    batch_size: usize,
+    id: TransferId,
+    msgs: Vec<T>,
+}
+
+impl<OT, T> BufferedRCOTSender<OT, T> {
+    /// Creates a new buffered sender.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The underlying random COT sender.
+    /// * `batch_size` - The number of correlations to request from the underlying sender at a time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `batch_size` is zero.
+    pub fn new(inner: OT, batch_size: usize) -> Self {
+        assert!(batch_size > 0, "batch size must be greater than zero");
+
+        Self {
+            inner,
+            batch_size,
+            id: TransferId::default(),
+            msgs: Vec::new(),
+        }
+    }
+
+    /// Returns the number of buffered correlations.
+    pub fn buffered(&self) -> usize {
+        self.msgs.len()
+    }
+
+    /// Returns the underlying sender, discarding any buffered correlations.
+    pub fn into_inner(self) -> OT {
+        self.inner
+    }
+}
+
+#[async_trait]
+impl<Ctx, OT, T> OTSetup<Ctx> for BufferedRCOTSender<OT, T>
+where
+    Ctx: Context,
+    OT: OTSetup<Ctx> + Send,
+    T: Send,
+{
+    async fn setup(&mut self, ctx: &mut Ctx) -> Result<(), OTError> {
+        self.inner.setup(ctx).await
+    }
+}
+
+#[async_trait]
+impl<Ctx, OT, T> RandomCOTSender<Ctx, T> for BufferedRCOTSender<OT, T>
+where
+    Ctx: Context,
+    OT: RandomCOTSender<Ctx, T> + Send,
+    T: Send,
+{
+    async fn send_random_correlated(
+        &mut self,
+        ctx: &mut Ctx,
+        count: usize,
+    ) -> Result<RCOTSenderOutput<T>, OTError> {
+        if self.msgs.len() < count {
+            let batches = (count - self.msgs.len()).div_ceil(self.batch_size);
+            let RCOTSenderOutput { id, msgs } = self
+                .inner
+                .send_random_correlated(ctx, batches * self.batch_size)
+                .await?;
+
+            self.id = id;
+            self.msgs.extend(msgs);
+        }
+
+        Ok(RCOTSenderOutput {
+            id: self.id,
+            msgs: self.msgs.drain(..count).collect(),
+        })
+    }
+}
+
+/// A random COT receiver which buffers correlations from an underlying receiver.
+///
+/// See the [module level documentation](self) for the meaning of the transfer ids it returns.
+#[derive(Debug)]
+pub struct BufferedRCOTReceiver<OT, T, U> {
+    inner: OT,
+    batch_size: usize,
+    id: TransferId,
+    choices: Vec<T>,
+    msgs: Vec<U>,
+}
+
+impl<OT, T, U> BufferedRCOTReceiver<OT, T, U> {
+    /// Creates a new buffered receiver.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The underlying random COT receiver.
+    /// * `batch_size` - The number of correlations to request from the underlying receiver at a time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `batch_size` is zero.
+    pub fn new(inner: OT, batch_size: usize) -> Self {
+        assert!(batch_size > 0, "batch size must be greater than zero");
+
+        Self {
+            inner,
+            batch_size,
+            id: TransferId::default(),
+            choices: Vec::new(),
+            msgs: Vec::new(),
+        }
+    }
+
+    /// Returns the number of buffered correlations.
+    pub fn buffered(&self) -> usize {
+        self.msgs.len()
+    }
+
+    /// Returns the underlying receiver, discarding any buffered correlations.
+    pub fn into_inner(self) -> OT {
+        self.inner
+    }
+}
+
+#[async_trait]
+impl<Ctx, OT, T, U> OTSetup<Ctx> for BufferedRCOTReceiver<OT, T, U>
+where
+    Ctx: Context,
+    OT: OTSetup<Ctx> + Send,
+    T: Send,
+    U: Send,
+{
+    async fn setup(&mut self, ctx: &mut Ctx) -> Result<(), OTError> {
+        self.inner.setup(ctx).await
+    }
+}
+
+#[async_trait]
+impl<Ctx, OT, T, U> RandomCOTReceiver<Ctx, T, U> for BufferedRCOTReceiver<OT, T, U>
+where
+    Ctx: Context,
+    OT: RandomCOTReceiver<Ctx, T, U> + Send,
+    T: Send,
+    U: Send,
+{
+    async fn receive_random_correlated(
+        &mut self,
+        ctx: &mut Ctx,
+        count: usize,
+    ) -> Result<RCOTReceiverOutput<T, U>, OTError> {
+        if self.msgs.len() < count {
+            let batches = (count - self.msgs.len()).div_ceil(self.batch_size);
+            let RCOTReceiverOutput { id, choices, msgs } = self
+                .inner
+                .receive_random_correlated(ctx, batches * self.batch_size)
+                .await?;
+
+            self.id = id;
+            self.choices.extend(choices);
+            self.msgs.extend(msgs);
+        }
+
+        Ok(RCOTReceiverOutput {
+            id: self.id,
+            choices: self.choices.drain(..count).collect(),
+            msgs: self.msgs.drain(..count).collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ideal::cot::{ideal_rcot, IdealCOTSender};
+    use mpz_common::executor::test_st_executor;
+    use mpz_core::Block;
+    use mpz_ot_core::{ideal::cot::IdealCOT, test::assert_cot};
+
+    /// Counts the calls made to, and correlations drawn from, the underlying sender.
+    struct CountingSender {
+        inner: IdealCOTSender,
+        calls: usize,
+        drawn: usize,
+    }
+
+    #[async_trait]
+    impl<Ctx: Context> RandomCOTSender<Ctx, Block> for CountingSender {
+        async fn send_random_correlated(
+            &mut self,
+            ctx: &mut Ctx,
+            count: usize,
+        ) -> Result<RCOTSenderOutput<Block>, OTError> {
+            self.calls += 1;
+            self.drawn += count;
+            self.inner.send_random_correlated(ctx, count).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_buffered_rcot() {
+        let (mut ctx_a, mut ctx_b) = test_st_executor(8);
+        let (alice, bob) = ideal_rcot();
+
+        let delta = IdealCOT::default().delta();
+
+        let mut alice = BufferedRCOTSender::new(
+            CountingSender {
+                inner: alice,
+                calls: 0,
+                drawn: 0,
+            },
+            16,
+        );
+        let mut bob = BufferedRCOTReceiver::new(bob, 16);
+
+        for count in [5, 11, 20, 1, 0] {
+            let (
+                RCOTSenderOutput {
+                    id: id_a,
+                    msgs: sender_msgs,
+                },
+                RCOTReceiverOutput {
+                    id: id_b,
+                    choices,
+                    msgs: receiver_msgs,
+                },
+            ) = tokio::try_join!(
+                alice.send_random_correlated(&mut ctx_a, count),
+                bob.receive_random_correlated(&mut ctx_b, count)
+            )
+            .unwrap();
+
+            assert_eq!(id_a, id_b);
+            assert_eq!(count, sender_msgs.len());
+            assert_eq!(count, receiver_msgs.len());
+            assert_eq!(count, choices.len());
+            assert_cot(delta, &choices, &sender_msgs, &receiver_msgs);
+            assert_eq!(alice.buffered(), bob.buffered());
+        }
+
+        // 37 correlations were consumed out of 3 batches. The first batch serves the first two
+        // requests, the third request draws two batches at once, and the last two requests are
+        // served from the buffer.
+        assert_eq!(alice.buffered(), 11);
+
+        let inner = alice.into_inner();
+        assert_eq!(inner.calls, 2);
+        assert_eq!(inner.drawn, 3 * 16);
+    }
+}
@@ -8,7 +8,8 @@ use mpz_common::{
 };
 use mpz_core::Block;
 use mpz_ot_core::{
-    ideal::cot::IdealCOT, COTReceiverOutput, COTSenderOutput, RCOTReceiverOutput, RCOTSenderOutput,
+    ideal::cot::{IdealCOT, IdealCOTError},
+    COTReceiverOutput, COTSenderOutput, RCOTReceiverOutput, RCOTSenderOutput,
 };
 
 use crate::{COTReceiver, COTSender, OTError, OTSetup, RandomCOTReceiver, RandomCOTSender};
@@ -17,10 +18,14 @@ fn cot(
     f: &mut IdealCOT,
     sender_count: usize,
     choices: Vec<bool>,
-) -> (COTSenderOutput<Block>, COTReceiverOutput<Block>) {
-    assert_eq!(sender_count, choices.len());
-
-    f.correlated(choices)
+) -> (
+    Result<COTSenderOutput<Block>, IdealCOTError>,
+    Result<COTReceiverOutput<Block>, IdealCOTError>,
+) {
+    match f.try_correlated(sender_count, choices) {
+        Ok((sender_output, receiver_output)) => (Ok(sender_output), Ok(receiver_output)),
+        Err(err) => (Err(err.clone()), Err(err)),
+    }
 }
 
 fn rcot(
@@ -82,7 +87,10 @@ impl<Ctx: Context> COTSender<Ctx, Block> for IdealCOTSender {
         ctx: &mut Ctx,
         count: usize,
     ) -> Result<COTSenderOutput<Block>, OTError> {
-        Ok(self.0.call(ctx, count, cot).await)
+        self.0
+            .call(ctx, count, cot)
+            .await
+            .map_err(|err| OTError::SenderError(Box::new(err)))
     }
 }
 
@@ -134,7 +142,10 @@ impl<Ctx: Context> COTReceiver<Ctx, bool, Block> for IdealCOTReceiver {
         ctx: &mut Ctx,
         choices: &[bool],
     ) -> Result<COTReceiverOutput<Block>, OTError> {
-        Ok(self.0.call(ctx, choices.to_vec(), cot).await)
+        self.0
+            .call(ctx, choices.to_vec(), cot)
+            .await
+            .map_err(|err| OTError::ReceiverError(Box::new(err)))
     }
 }
 
@@ -189,6 +200,20 @@ mod tests {
         assert_cot(delta, &choices, &sender_msgs, &receiver_msgs);
     }
 
+    #[tokio::test]
+    async fn test_ideal_cot_length_mismatch() {
+        let (mut ctx_a, mut ctx_b) = test_st_executor(8);
+        let (mut alice, mut bob) = ideal_cot();
+
+        let (sender_output, receiver_output) = tokio::join!(
+            alice.send_correlated(&mut ctx_a, 10),
+            bob.receive_correlated(&mut ctx_b, &[false; 9])
+        );
+
+        assert!(matches!(sender_output, Err(OTError::SenderError(_))));
+        assert!(matches!(receiver_output, Err(OTError::ReceiverError(_))));
+    }
+
     #[tokio::test]
     async fn test_ideal_rcot() {
         let (mut ctx_a, mut ctx_b) = test_st_executor(8);
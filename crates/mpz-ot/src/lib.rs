@@ -9,6 +9,7 @@
     clippy::all
 )]
 
+pub mod buffered;
 pub mod chou_orlandi;
 #[cfg(any(test, feature = "ideal"))]
 pub mod ideal;